@@ -0,0 +1,207 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// User-defined mapping from directory name patterns to the marker file that
+/// should be created inside a matching directory.
+#[derive(Debug, Deserialize, Default)]
+pub struct Config {
+    #[serde(default)]
+    pub markers: Vec<MarkerRule>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MarkerRule {
+    pub patterns: Vec<String>,
+    pub marker_file: String,
+}
+
+/// The built-in rules, kept in sync with the defaults deja-dup-auto-ignore
+/// has always shipped with. Suppressed by `--no-default-ignore`.
+pub fn default_marker_rules() -> Vec<MarkerRule> {
+    vec![
+        MarkerRule {
+            patterns: [
+                "node_modules",
+                "venv",
+                ".venv",
+                ".gradle",
+                "target",
+                "build",
+                "out",
+                "dist",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+            marker_file: ".deja-dup-ignore".to_string(),
+        },
+        MarkerRule {
+            patterns: vec!["*cache*".to_string()],
+            marker_file: "CACHEDIR.TAG".to_string(),
+        },
+    ]
+}
+
+impl Config {
+    /// Loads the config file at `$XDG_CONFIG_HOME/deja-dup-auto-ignore/config.toml`
+    /// (or `~/.config/deja-dup-auto-ignore/config.toml`), returning an empty
+    /// config if it doesn't exist.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&config_path())
+    }
+
+    /// Loads a config from an explicit path, returning an empty config if it
+    /// doesn't exist. Split out from `load` so tests can point at a fixture
+    /// file instead of the real config location.
+    fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse config file: {}", path.display()))
+    }
+}
+
+fn config_path() -> PathBuf {
+    let config_dir = match std::env::var("XDG_CONFIG_HOME") {
+        Ok(xdg_config_home) => PathBuf::from(xdg_config_home),
+        Err(_) => PathBuf::from(shellexpand::tilde("~/.config").as_ref()),
+    };
+    config_dir.join("deja-dup-auto-ignore").join("config.toml")
+}
+
+/// Combines the built-in marker rules with the user's configured ones. User
+/// rules are checked first by `find_matching_rule`, so a config entry can
+/// override a default that targets the same name (e.g. a custom `target`
+/// mapping) without the user having to repeat every other default they
+/// still want. `no_default_ignore` suppresses the built-ins entirely.
+pub fn build_marker_rules(config: Config, no_default_ignore: bool) -> Vec<MarkerRule> {
+    let mut rules = config.markers;
+    if !no_default_ignore {
+        rules.extend(default_marker_rules());
+    }
+    rules
+}
+
+/// Matches `name` against `pattern`. A pattern wrapped in `*...*` matches
+/// anywhere in the name (e.g. `*cache*`); any other pattern must match
+/// exactly.
+pub fn pattern_matches(pattern: &str, name: &str) -> bool {
+    match pattern.strip_prefix('*').and_then(|p| p.strip_suffix('*')) {
+        Some(inner) => name.contains(inner),
+        None => name == pattern,
+    }
+}
+
+/// Finds the first rule (in order) whose patterns match `file_name`.
+pub fn find_matching_rule<'a>(rules: &'a [MarkerRule], file_name: &str) -> Option<&'a MarkerRule> {
+    rules
+        .iter()
+        .find(|rule| rule.patterns.iter().any(|p| pattern_matches(p, file_name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_matches_exact() {
+        assert!(pattern_matches("target", "target"));
+        assert!(!pattern_matches("target", "my-target"));
+    }
+
+    #[test]
+    fn test_pattern_matches_wildcard() {
+        assert!(pattern_matches("*cache*", "cache"));
+        assert!(pattern_matches("*cache*", "pip-cache"));
+        assert!(!pattern_matches("*cache*", "target"));
+    }
+
+    #[test]
+    fn test_load_from_parses_config_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        fs::write(
+            &config_path,
+            r#"
+[[markers]]
+patterns = ["__pycache__", ".tox"]
+marker_file = ".deja-dup-ignore"
+
+[[markers]]
+patterns = ["Pods"]
+marker_file = "CACHEDIR.TAG"
+"#,
+        )
+        .unwrap();
+
+        let config = Config::load_from(&config_path).unwrap();
+        assert_eq!(config.markers.len(), 2);
+        assert_eq!(
+            config.markers[0].patterns,
+            vec!["__pycache__".to_string(), ".tox".to_string()]
+        );
+        assert_eq!(config.markers[0].marker_file, ".deja-dup-ignore");
+        assert_eq!(config.markers[1].patterns, vec!["Pods".to_string()]);
+        assert_eq!(config.markers[1].marker_file, "CACHEDIR.TAG");
+    }
+
+    #[test]
+    fn test_load_from_missing_file_returns_empty_config() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join("does-not-exist.toml");
+
+        let config = Config::load_from(&config_path).unwrap();
+        assert!(config.markers.is_empty());
+    }
+
+    #[test]
+    fn test_build_marker_rules_includes_defaults_by_default() {
+        let config = Config {
+            markers: vec![MarkerRule {
+                patterns: vec!["__pycache__".to_string()],
+                marker_file: ".deja-dup-ignore".to_string(),
+            }],
+        };
+
+        let rules = build_marker_rules(config, false);
+
+        assert!(find_matching_rule(&rules, "__pycache__").is_some());
+        assert!(find_matching_rule(&rules, "target").is_some());
+    }
+
+    #[test]
+    fn test_build_marker_rules_no_default_ignore_suppresses_defaults() {
+        let config = Config {
+            markers: vec![MarkerRule {
+                patterns: vec!["__pycache__".to_string()],
+                marker_file: ".deja-dup-ignore".to_string(),
+            }],
+        };
+
+        let rules = build_marker_rules(config, true);
+
+        assert!(find_matching_rule(&rules, "__pycache__").is_some());
+        assert!(find_matching_rule(&rules, "target").is_none());
+    }
+
+    #[test]
+    fn test_user_config_overrides_default_for_same_name() {
+        let config = Config {
+            markers: vec![MarkerRule {
+                patterns: vec!["target".to_string()],
+                marker_file: "CACHEDIR.TAG".to_string(),
+            }],
+        };
+
+        let rules = build_marker_rules(config, false);
+        let rule = find_matching_rule(&rules, "target").unwrap();
+
+        assert_eq!(rule.marker_file, "CACHEDIR.TAG");
+    }
+}
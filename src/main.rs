@@ -6,15 +6,30 @@ use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+mod config;
 mod stack_vec;
 mod traverse;
 
+use config::Config;
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
     /// Dry run mode - list directories without creating .deja-dup-ignore files
     #[arg(short = 'n', long)]
     dry_run: bool,
+
+    /// Don't read .gitignore, .hgignore, .git/info/exclude, or global git excludes
+    #[arg(long)]
+    no_vcs_ignore: bool,
+
+    /// Don't read any ignore files, including the generic .ignore
+    #[arg(long)]
+    no_ignore: bool,
+
+    /// Don't use the built-in directory name -> marker file mappings
+    #[arg(long)]
+    no_default_ignore: bool,
 }
 
 fn main() -> Result<()> {
@@ -29,27 +44,33 @@ fn main() -> Result<()> {
         .iter()
         .map(|p| p.as_path())
         .collect::<Vec<_>>();
-    let mut cb = if args.dry_run {
-        (|path: &Path| println!("{}", path.display())) as fn(&Path)
+    let ignore_options = traverse::IgnoreOptions {
+        no_vcs_ignore: args.no_vcs_ignore,
+        no_ignore: args.no_ignore,
+    };
+    let host_env = traverse::HostEnv::from_process_env();
+
+    let marker_rules = config::build_marker_rules(Config::load()?, args.no_default_ignore);
+
+    let mut cb: Box<dyn FnMut(&Path)> = if args.dry_run {
+        Box::new(|path: &Path| println!("{}", path.display()))
     } else {
-        (|path: &Path| {
+        Box::new(move |path: &Path| {
             let Some(file_name) = path.file_name().and_then(|s| s.to_str()) else {
                 return;
             };
-            let file_to_create = match file_name {
-                "node_modules" | "venv" | ".venv" | ".gradle" | "target" | "build" | "out"
-                | "dist" => ".deja-dup-ignore",
-                str if str.contains("cache") => "CACHEDIR.TAG",
-                _ => return,
+            let Some(rule) = config::find_matching_rule(&marker_rules, file_name) else {
+                return;
             };
-            match File::create(path.join(file_to_create)) {
-                Ok(_) => info!("Created {file_to_create} in {}", path.display()),
+            match File::create(path.join(&rule.marker_file)) {
+                Ok(_) => info!("Created {} in {}", rule.marker_file, path.display()),
                 Err(e) => warn!(
-                    "Failed to create {file_to_create} in {}: {e}",
+                    "Failed to create {} in {}: {e}",
+                    rule.marker_file,
                     path.display()
                 ),
             }
-        }) as fn(&Path)
+        })
     };
     for include_path in &include_paths {
         let canonical_root = fs::canonicalize(include_path).with_context(|| {
@@ -58,7 +79,13 @@ fn main() -> Result<()> {
                 include_path.display()
             )
         })?;
-        traverse::find_directory_to_ignore(&canonical_root, &exclude_paths, &mut cb)?;
+        traverse::find_directory_to_ignore(
+            &canonical_root,
+            &exclude_paths,
+            &ignore_options,
+            &host_env,
+            cb.as_mut(),
+        )?;
     }
 
     info!("Done!");
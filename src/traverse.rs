@@ -1,25 +1,190 @@
 use crate::stack_vec::StackVec;
 use anyhow::{Context, Result};
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
 use log::{debug, warn};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub type Callback<'a> = dyn FnMut(&Path) + 'a;
 
+/// Controls which ignore-file sources are consulted during traversal.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IgnoreOptions {
+    /// Skip `.gitignore`/`.hgignore`/`.git/info/exclude` (and the global
+    /// excludes file) but still honor the generic `.ignore`.
+    pub no_vcs_ignore: bool,
+    /// Skip all ignore-file loading, including the generic `.ignore`.
+    pub no_ignore: bool,
+}
+
+/// Host-environment inputs used to resolve ignore sources that live outside
+/// of the directories being traversed (the user's home directory and where
+/// the ancestor walk is allowed to look). Kept separate from the process
+/// environment so callers - tests in particular - can inject values instead
+/// of reading real host state.
+#[derive(Debug, Clone, Default)]
+pub struct HostEnv {
+    pub home_dir: Option<PathBuf>,
+    pub xdg_config_home: Option<PathBuf>,
+    /// Don't walk above this directory while looking for ancestor
+    /// `.gitignore`s. `None` means walk all the way to the filesystem root.
+    pub ancestor_boundary: Option<PathBuf>,
+}
+
+impl HostEnv {
+    /// Reads `$HOME` and `$XDG_CONFIG_HOME` from the real process
+    /// environment, with no ancestor boundary. This is what `main` uses.
+    pub fn from_process_env() -> Self {
+        Self {
+            home_dir: std::env::var_os("HOME").map(PathBuf::from),
+            xdg_config_home: std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from),
+            ancestor_boundary: None,
+        }
+    }
+}
+
 pub fn find_directory_to_ignore(
     root: &Path,
     exclude_paths: &[&Path],
+    ignore_options: &IgnoreOptions,
+    host_env: &HostEnv,
     cb: &mut Callback<'_>,
 ) -> Result<()> {
     let mut gitignore_stack = Vec::new();
-    traverse(root, exclude_paths, &mut gitignore_stack, cb)?;
+    if !ignore_options.no_ignore && !ignore_options.no_vcs_ignore {
+        if let Some(gitignore) = global_gitignore(root, host_env)? {
+            gitignore_stack.push(gitignore);
+        }
+        gitignore_stack.extend(ancestor_gitignores(root, host_env)?);
+    }
+    traverse(root, exclude_paths, ignore_options, &mut gitignore_stack, cb)?;
     Ok(())
 }
 
+/// Walks upward from `root`'s parent toward the filesystem root, loading every
+/// `.gitignore` found until a directory containing `.git` is reached. That
+/// directory's own `.gitignore` and `.git/info/exclude` are both loaded
+/// before stopping, consistent with how a directory's own repo root is
+/// treated during traversal. Also stops if `host_env.ancestor_boundary`
+/// would be left behind.
+/// Returned in lowest-to-highest priority order so callers can simply extend
+/// their stack with it before traversing into `root`.
+fn ancestor_gitignores(root: &Path, host_env: &HostEnv) -> Result<Vec<Gitignore>> {
+    let mut ancestors = Vec::new();
+
+    let mut dir = root.parent();
+    while let Some(d) = dir {
+        if let Some(boundary) = &host_env.ancestor_boundary {
+            if !d.starts_with(boundary) {
+                break;
+            }
+        }
+
+        let git_dir = d.join(".git");
+        let is_repo_root = git_dir.is_dir();
+
+        // `.git/info/exclude` goes first so it's the lowest-priority glob in
+        // the builder: the `ignore` crate resolves same-builder conflicts by
+        // last-glob-added, and real git has `.gitignore` outrank
+        // `$GIT_DIR/info/exclude` when both match the same path.
+        let mut paths = Vec::new();
+        if is_repo_root {
+            paths.push(git_dir.join("info").join("exclude"));
+        }
+        paths.push(d.join(".gitignore"));
+        if let Some(gitignore) = build_gitignore(d, &paths)? {
+            ancestors.push(gitignore);
+        }
+
+        if is_repo_root {
+            break;
+        }
+        dir = d.parent();
+    }
+
+    ancestors.reverse();
+    Ok(ancestors)
+}
+
+/// Resolves and loads the user's global excludes file, i.e. the file pointed
+/// to by `core.excludesFile` in `~/.gitconfig`, falling back to
+/// `$XDG_CONFIG_HOME/git/ignore` or `~/.config/git/ignore`.
+fn global_gitignore(root: &Path, host_env: &HostEnv) -> Result<Option<Gitignore>> {
+    let Some(excludes_path) = global_excludes_file(host_env)? else {
+        return Ok(None);
+    };
+    build_gitignore(root, &[excludes_path])
+}
+
+fn global_excludes_file(host_env: &HostEnv) -> Result<Option<PathBuf>> {
+    if let Some(path) = git_config_excludes_file(host_env)? {
+        return Ok(Some(path));
+    }
+
+    let config_dir = match &host_env.xdg_config_home {
+        Some(xdg_config_home) => xdg_config_home.clone(),
+        None => match &host_env.home_dir {
+            Some(home_dir) => home_dir.join(".config"),
+            None => return Ok(None),
+        },
+    };
+    Ok(Some(config_dir.join("git").join("ignore")))
+}
+
+/// A minimal reader for the one setting we care about; not a general
+/// gitconfig parser.
+fn git_config_excludes_file(host_env: &HostEnv) -> Result<Option<PathBuf>> {
+    let Some(home_dir) = &host_env.home_dir else {
+        return Ok(None);
+    };
+
+    let gitconfig_path = home_dir.join(".gitconfig");
+    if !gitconfig_path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&gitconfig_path)
+        .with_context(|| format!("Failed to read {}", gitconfig_path.display()))?;
+
+    let mut in_core_section = false;
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(section) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            in_core_section = section.trim().eq_ignore_ascii_case("core");
+            continue;
+        }
+        if !in_core_section {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if key.trim().eq_ignore_ascii_case("excludesFile") {
+            return Ok(Some(expand_home(value.trim(), home_dir)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Expands a leading `~` in `path` against `home_dir`, the same way git
+/// expands `core.excludesFile`. Unlike `shellexpand::tilde`, this is driven
+/// by the caller-supplied home directory rather than the real `$HOME`.
+fn expand_home(path: &str, home_dir: &Path) -> PathBuf {
+    if let Some(rest) = path.strip_prefix("~/") {
+        return home_dir.join(rest);
+    }
+    if path == "~" {
+        return home_dir.to_path_buf();
+    }
+    PathBuf::from(path)
+}
+
 fn traverse(
     dir: &Path,
     exclude_paths: &[&Path],
+    ignore_options: &IgnoreOptions,
     gitignore_stack: &mut Vec<Gitignore>,
     cb: &mut Callback<'_>,
 ) -> Result<()> {
@@ -41,22 +206,16 @@ fn traverse(
         return Ok(());
     }
 
-    let mut is_ignored = false;
-    for gitignore in gitignore_stack.iter() {
-        let matched = gitignore.matched(dir, true);
-        if matched.is_ignore() {
-            is_ignored = true;
-            break;
-        }
-    }
-    if is_ignored {
+    if is_effectively_ignored(dir, gitignore_stack) {
         cb(dir);
         return Ok(());
     }
 
     let mut gitignore_stack = StackVec::new(gitignore_stack);
-    if let Some(gitignore) = maybe_build_gitignore(dir)? {
-        gitignore_stack.push(gitignore);
+    if !ignore_options.no_ignore {
+        if let Some(gitignore) = maybe_build_gitignore(dir, ignore_options)? {
+            gitignore_stack.push(gitignore);
+        }
     }
 
     let entries: Vec<_> = match fs::read_dir(dir) {
@@ -92,20 +251,99 @@ fn traverse(
         }
 
         let path = entry.path();
-        traverse(&path, &next_exclude_paths, gitignore_stack.inner(), cb)?;
+        traverse(
+            &path,
+            &next_exclude_paths,
+            ignore_options,
+            gitignore_stack.inner(),
+            cb,
+        )?;
     }
 
     Ok(())
 }
 
-fn maybe_build_gitignore(dir: &Path) -> Result<Option<Gitignore>> {
-    let mut builder = GitignoreBuilder::new(dir);
+/// Resolves whether `dir` is ignored by checking the stack nearest-first,
+/// so a more specific gitignore takes precedence over a more general one
+/// the way git itself resolves it. The first definitive match wins: a `!`
+/// whitelist re-include stops the search and clears the ignore, while a
+/// plain ignore match stops it and sets it. `matched_path_or_any_parents`
+/// is used (rather than `matched`) so a directory is still marked if an
+/// ancestor segment within that gitignore's root is ignored, even when the
+/// intermediate directories weren't individually rebuilt into the stack.
+fn is_effectively_ignored(dir: &Path, gitignore_stack: &[Gitignore]) -> bool {
+    for gitignore in gitignore_stack.iter().rev() {
+        match gitignore.matched_path_or_any_parents(dir, true) {
+            Match::Ignore(_) => return true,
+            Match::Whitelist(_) => return false,
+            Match::None => {}
+        }
+    }
+    false
+}
+
+/// Names of the per-directory VCS ignore files we understand, in addition to
+/// the generic `.ignore` and the repo-level `.git/info/exclude`, both handled
+/// separately below.
+const VCS_IGNORE_FILE_NAMES: &[&str] = &[".gitignore", ".hgignore"];
+
+fn maybe_build_gitignore(dir: &Path, ignore_options: &IgnoreOptions) -> Result<Option<Gitignore>> {
+    let mut paths = vec![dir.join(".ignore")];
+
+    if !ignore_options.no_vcs_ignore {
+        // `.git/info/exclude` goes before `.gitignore`/`.hgignore` so it ranks
+        // below them in the builder (only the generic `.ignore`, added above,
+        // ranks lower): the `ignore` crate resolves same-builder conflicts by
+        // last-glob-added, and real git has `.gitignore`/`.hgignore` outrank
+        // `$GIT_DIR/info/exclude` when both match the same path.
+        let git_dir = dir.join(".git");
+        if git_dir.is_dir() {
+            paths.push(git_dir.join("info").join("exclude"));
+        }
+
+        let hgignore = dir.join(".hgignore");
+        if hgignore.exists() {
+            warn_if_hgignore_not_glob(&hgignore);
+        }
+        paths.extend(VCS_IGNORE_FILE_NAMES.iter().map(|name| dir.join(name)));
+    }
+
+    build_gitignore(dir, &paths)
+}
+
+/// Mercurial's default `.hgignore` syntax is regex, not gitignore globs -
+/// only a leading `syntax: glob` line switches a file into the glob syntax
+/// we actually parse it as here. Most real-world `.hgignore` files rely on
+/// the regex default, so warn rather than silently mis-parsing regex
+/// patterns as nonsensical globs with no diagnostic.
+fn warn_if_hgignore_not_glob(path: &Path) {
+    let Ok(content) = fs::read_to_string(path) else {
+        return;
+    };
+
+    let starts_with_glob_syntax = content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .is_some_and(|line| line.replace(' ', "").eq_ignore_ascii_case("syntax:glob"));
+
+    if !starts_with_glob_syntax {
+        warn!(
+            "{} does not start with 'syntax: glob'; Mercurial's default regex ignore syntax is not supported and its patterns may be misinterpreted as globs",
+            path.display()
+        );
+    }
+}
+
+fn build_gitignore(base: &Path, paths: &[PathBuf]) -> Result<Option<Gitignore>> {
+    let mut builder = GitignoreBuilder::new(base);
     let mut has_new_gitignore = false;
 
-    let gitignore_path = dir.join(".gitignore");
-    if gitignore_path.exists() {
-        builder.add(&gitignore_path);
-        has_new_gitignore = true;
+    for path in paths {
+        if path.exists() {
+            builder.add(path);
+            has_new_gitignore = true;
+        }
     }
 
     has_new_gitignore
@@ -131,6 +369,30 @@ mod tests {
         File(&'static str),
     }
 
+    /// A `HostEnv` fully isolated from the real host: no home directory to
+    /// read a global gitignore from, and the ancestor walk is confined to
+    /// `root` so it can never pick up stray ignore files from the test
+    /// runner's actual filesystem.
+    fn isolated_host_env(root: &Path) -> HostEnv {
+        HostEnv {
+            home_dir: None,
+            xdg_config_home: None,
+            ancestor_boundary: Some(root.to_path_buf()),
+        }
+    }
+
+    /// Same isolation as `isolated_host_env`, but without an ancestor
+    /// boundary, for tests that specifically exercise the ancestor walk
+    /// (which always stops once it hits a `.git` directory in this module's
+    /// own fixtures, so it never escapes into the real filesystem).
+    fn isolated_host_env_unbounded() -> HostEnv {
+        HostEnv {
+            home_dir: None,
+            xdg_config_home: None,
+            ancestor_boundary: None,
+        }
+    }
+
     impl TestCase {
         fn run(&self) {
             let temp_dir = tempfile::tempdir().unwrap();
@@ -157,10 +419,16 @@ mod tests {
             let exclude_paths: Vec<_> = self.exclude_paths.iter().map(|p| root.join(p)).collect();
             let exclude_refs: Vec<_> = exclude_paths.iter().map(|p| p.as_path()).collect();
 
-            find_directory_to_ignore(root, &exclude_refs, &mut |path| {
-                let rel_path = path.strip_prefix(root).unwrap();
-                results.push(rel_path.to_string_lossy().to_string());
-            })
+            find_directory_to_ignore(
+                root,
+                &exclude_refs,
+                &IgnoreOptions::default(),
+                &isolated_host_env(root),
+                &mut |path| {
+                    let rel_path = path.strip_prefix(root).unwrap();
+                    results.push(rel_path.to_string_lossy().to_string());
+                },
+            )
             .unwrap();
 
             // Verify results
@@ -343,6 +611,300 @@ mod tests {
         .run();
     }
 
+    #[test]
+    fn test_additional_ignore_file_formats() {
+        TestCase {
+            name: "additional ignore file formats",
+            structure: &[
+                (".ignore", FileType::File("from_dot_ignore/")),
+                ("from_dot_ignore", FileType::Dir),
+                (".hgignore", FileType::File("from_hgignore/")),
+                ("from_hgignore", FileType::Dir),
+                (".git", FileType::Dir),
+                (".git/info/exclude", FileType::File("from_git_exclude/")),
+                ("from_git_exclude", FileType::Dir),
+                ("not_ignored", FileType::Dir),
+            ],
+            exclude_paths: &[],
+            expected: &["from_dot_ignore", "from_hgignore", "from_git_exclude"],
+        }
+        .run();
+    }
+
+    #[test]
+    fn test_gitignore_whitelist_wins_over_conflicting_git_info_exclude() {
+        // .gitignore re-includes `foo/` while .git/info/exclude tries to
+        // ignore it again; real git precedence has .gitignore win, so
+        // .git/info/exclude must be treated as lower priority.
+        TestCase {
+            name: "gitignore whitelist wins over conflicting git/info/exclude",
+            structure: &[
+                (".gitignore", FileType::File("foo/\n!foo/\n")),
+                (".git", FileType::Dir),
+                (".git/info/exclude", FileType::File("foo/\n")),
+                ("foo", FileType::Dir),
+            ],
+            exclude_paths: &[],
+            expected: &[],
+        }
+        .run();
+    }
+
+    #[test]
+    fn test_hgignore_without_glob_syntax_warns_but_still_applies_as_glob() {
+        // Without a `syntax: glob` header this is technically a regex
+        // .hgignore, which we don't support; we still fall back to treating
+        // it as a glob (and log a warning, which this test does not check)
+        // rather than erroring out.
+        TestCase {
+            name: "hgignore without glob syntax header",
+            structure: &[
+                (".hgignore", FileType::File("ignored/\n")),
+                ("ignored", FileType::Dir),
+                ("not_ignored", FileType::Dir),
+            ],
+            exclude_paths: &[],
+            expected: &["ignored"],
+        }
+        .run();
+    }
+
+    #[test]
+    fn test_ancestor_gitignore() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_root = temp_dir.path();
+        fs::create_dir_all(repo_root.join(".git")).unwrap();
+        fs::write(repo_root.join(".gitignore"), "ignored/\n").unwrap();
+
+        let include_root = repo_root.join("sub");
+        fs::create_dir_all(include_root.join("ignored")).unwrap();
+        fs::create_dir_all(include_root.join("not_ignored")).unwrap();
+
+        let mut results = Vec::new();
+        find_directory_to_ignore(
+            &include_root,
+            &[],
+            &IgnoreOptions::default(),
+            &isolated_host_env_unbounded(),
+            &mut |path| {
+                let rel_path = path.strip_prefix(&include_root).unwrap();
+                results.push(rel_path.to_string_lossy().to_string());
+            },
+        )
+        .unwrap();
+
+        assert_eq!(results, vec!["ignored".to_string()]);
+    }
+
+    #[test]
+    fn test_no_vcs_ignore_skips_gitignore_but_not_dot_ignore() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "from_gitignore/\n").unwrap();
+        fs::write(root.join(".ignore"), "from_dot_ignore/\n").unwrap();
+        fs::create_dir_all(root.join("from_gitignore")).unwrap();
+        fs::create_dir_all(root.join("from_dot_ignore")).unwrap();
+
+        let mut results = Vec::new();
+        let ignore_options = IgnoreOptions {
+            no_vcs_ignore: true,
+            no_ignore: false,
+        };
+        find_directory_to_ignore(
+            root,
+            &[],
+            &ignore_options,
+            &isolated_host_env(root),
+            &mut |path| {
+                let rel_path = path.strip_prefix(root).unwrap();
+                results.push(rel_path.to_string_lossy().to_string());
+            },
+        )
+        .unwrap();
+
+        assert_eq!(results, vec!["from_dot_ignore".to_string()]);
+    }
+
+    #[test]
+    fn test_no_ignore_skips_everything() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "from_gitignore/\n").unwrap();
+        fs::write(root.join(".ignore"), "from_dot_ignore/\n").unwrap();
+        fs::create_dir_all(root.join("from_gitignore")).unwrap();
+        fs::create_dir_all(root.join("from_dot_ignore")).unwrap();
+
+        let mut results = Vec::new();
+        let ignore_options = IgnoreOptions {
+            no_vcs_ignore: false,
+            no_ignore: true,
+        };
+        find_directory_to_ignore(
+            root,
+            &[],
+            &ignore_options,
+            &isolated_host_env(root),
+            &mut |path| {
+                let rel_path = path.strip_prefix(root).unwrap();
+                results.push(rel_path.to_string_lossy().to_string());
+            },
+        )
+        .unwrap();
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_ancestor_git_info_exclude_is_honored() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_root = temp_dir.path();
+        fs::create_dir_all(repo_root.join(".git").join("info")).unwrap();
+        fs::write(
+            repo_root.join(".git").join("info").join("exclude"),
+            "ignored/\n",
+        )
+        .unwrap();
+
+        let include_root = repo_root.join("sub");
+        fs::create_dir_all(include_root.join("ignored")).unwrap();
+        fs::create_dir_all(include_root.join("not_ignored")).unwrap();
+
+        let mut results = Vec::new();
+        find_directory_to_ignore(
+            &include_root,
+            &[],
+            &IgnoreOptions::default(),
+            &isolated_host_env_unbounded(),
+            &mut |path| {
+                let rel_path = path.strip_prefix(&include_root).unwrap();
+                results.push(rel_path.to_string_lossy().to_string());
+            },
+        )
+        .unwrap();
+
+        assert_eq!(results, vec!["ignored".to_string()]);
+    }
+
+    #[test]
+    fn test_ancestor_gitignore_whitelist_wins_over_conflicting_git_info_exclude() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_root = temp_dir.path();
+        fs::create_dir_all(repo_root.join(".git").join("info")).unwrap();
+        fs::write(repo_root.join(".gitignore"), "foo/\n!foo/\n").unwrap();
+        fs::write(
+            repo_root.join(".git").join("info").join("exclude"),
+            "foo/\n",
+        )
+        .unwrap();
+
+        let include_root = repo_root.join("sub");
+        fs::create_dir_all(include_root.join("foo")).unwrap();
+
+        let mut results = Vec::new();
+        find_directory_to_ignore(
+            &include_root,
+            &[],
+            &IgnoreOptions::default(),
+            &isolated_host_env_unbounded(),
+            &mut |path| {
+                let rel_path = path.strip_prefix(&include_root).unwrap();
+                results.push(rel_path.to_string_lossy().to_string());
+            },
+        )
+        .unwrap();
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_global_excludes_file_is_read_from_injected_home_dir() {
+        let fake_home = tempfile::tempdir().unwrap();
+        fs::write(
+            fake_home.path().join(".gitconfig"),
+            "[core]\n\texcludesFile = ~/.config/git/ignore\n",
+        )
+        .unwrap();
+        fs::create_dir_all(fake_home.path().join(".config/git")).unwrap();
+        fs::write(
+            fake_home.path().join(".config/git/ignore"),
+            "from_global_excludes/\n",
+        )
+        .unwrap();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("from_global_excludes")).unwrap();
+        fs::create_dir_all(root.join("not_ignored")).unwrap();
+
+        let host_env = HostEnv {
+            home_dir: Some(fake_home.path().to_path_buf()),
+            xdg_config_home: None,
+            ancestor_boundary: Some(root.to_path_buf()),
+        };
+
+        let mut results = Vec::new();
+        find_directory_to_ignore(
+            root,
+            &[],
+            &IgnoreOptions::default(),
+            &host_env,
+            &mut |path| {
+                let rel_path = path.strip_prefix(root).unwrap();
+                results.push(rel_path.to_string_lossy().to_string());
+            },
+        )
+        .unwrap();
+
+        assert_eq!(results, vec!["from_global_excludes".to_string()]);
+    }
+
+    #[test]
+    fn test_nearer_whitelist_wins_over_further_ignore() {
+        TestCase {
+            name: "nearer whitelist wins over further ignore",
+            structure: &[
+                (".gitignore", FileType::File("sub/build/\n")),
+                ("sub", FileType::Dir),
+                ("sub/.gitignore", FileType::File("!build/\n")),
+                ("sub/build", FileType::Dir),
+                ("sub/other", FileType::Dir),
+            ],
+            exclude_paths: &[],
+            expected: &[],
+        }
+        .run();
+    }
+
+    #[test]
+    fn test_ancestor_gitignore_matches_intermediate_segment() {
+        // The include root itself is a level below the directory an
+        // ancestor .gitignore actually ignores; none of the intermediate
+        // directories are ever traversed individually, so only
+        // `matched_path_or_any_parents` (not a plain `matched`) catches this.
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_root = temp_dir.path();
+        fs::create_dir_all(repo_root.join(".git")).unwrap();
+        fs::write(repo_root.join(".gitignore"), "mid/\n").unwrap();
+
+        let include_root = repo_root.join("mid").join("leaf");
+        fs::create_dir_all(&include_root).unwrap();
+
+        let mut results = Vec::new();
+        find_directory_to_ignore(
+            &include_root,
+            &[],
+            &IgnoreOptions::default(),
+            &isolated_host_env_unbounded(),
+            &mut |path| {
+                let rel_path = path.strip_prefix(&include_root).unwrap();
+                results.push(rel_path.to_string_lossy().to_string());
+            },
+        )
+        .unwrap();
+
+        assert_eq!(results, vec![String::new()]);
+    }
+
     #[test]
     fn test_complex_scenario() {
         TestCase {